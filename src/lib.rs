@@ -24,6 +24,11 @@
 
 use futures::future::BoxFuture;
 
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "stream")]
+pub use stream::*;
+
 /// Wrap a future that may or may not be marked must_use with a newtype
 /// that is marked must_use.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -100,6 +105,38 @@ impl<'lt, T> From<BoxFuture<'lt, T>> for MustBoxFuture<'lt, T> {
     }
 }
 
+impl<'lt, T> MustBoxFuture<'lt, T> {
+    /// Construct a new MustBoxFuture from a type that implements
+    /// `IntoFuture` rather than `Future` directly, e.g. a builder-style
+    /// type returned from an ecosystem API.
+    pub fn from_into<I>(i: I) -> Self
+    where
+        I: 'lt + std::future::IntoFuture<Output = T>,
+        I::IntoFuture: 'lt + Send,
+    {
+        Self {
+            sub_fut: futures::future::FutureExt::boxed(i.into_future()),
+        }
+    }
+}
+
+impl<T: ?Sized> IntoMustFuture for T where T: std::future::IntoFuture {}
+
+/// Helper trait for converting types that implement `IntoFuture` (but not
+/// necessarily `Future` directly, e.g. builder-style types) into
+/// MustBoxFutures. Would be nice to `impl<I: IntoFuture> Into<MustBoxFuture>
+/// for I`, but blanket impls in rust core prevent this.
+pub trait IntoMustFuture: std::future::IntoFuture {
+    /// Convert this `IntoFuture` into a MustBoxFuture
+    fn must_box_into<'a>(self) -> MustBoxFuture<'a, Self::Output>
+    where
+        Self: 'a + Sized,
+        Self::IntoFuture: 'a + Send,
+    {
+        MustBoxFuture::from_into(self)
+    }
+}
+
 impl<'lt, T> std::future::Future for MustBoxFuture<'lt, T> {
     type Output = T;
 
@@ -111,12 +148,146 @@ impl<'lt, T> std::future::Future for MustBoxFuture<'lt, T> {
     }
 }
 
+impl<'lt, T> MustBoxFuture<'lt, T> {
+    /// Poll this future a single step without an executor, using a no-op
+    /// waker. Returns `Some(output)` if the future was already ready to
+    /// complete, else drops the future and returns `None`. Useful in tests
+    /// and for opportunistic fast-paths where a value may already be
+    /// available.
+    pub fn now_or_never(mut self) -> Option<T> {
+        let waker = futures::task::noop_waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+        match std::future::Future::poll(std::pin::Pin::new(&mut self), &mut cx) {
+            std::task::Poll::Ready(t) => Some(t),
+            std::task::Poll::Pending => None,
+        }
+    }
+
+    /// Poll this future a single step without an executor, preserving the
+    /// future if it is still pending.
+    pub fn poll_immediate(&mut self, cx: &mut std::task::Context) -> std::task::Poll<T> {
+        std::future::Future::poll(self.sub_fut.as_mut(), cx)
+    }
+}
+
 impl<'lt, T> std::fmt::Debug for MustBoxFuture<'lt, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MustBoxFuture").finish()
     }
 }
 
+/// Join two futures, returning a [MustBoxFuture] that resolves to a tuple
+/// of both outputs once both futures have completed. This gives
+/// `futures::future::join` the same leak-guarding behavior as the rest of
+/// this crate without having to remember to wrap the result by hand.
+pub fn must_join<'lt, A, B>(a: A, b: B) -> MustBoxFuture<'lt, (A::Output, B::Output)>
+where
+    A: 'lt + std::future::Future + Send,
+    B: 'lt + std::future::Future + Send,
+    A::Output: Send,
+    B::Output: Send,
+{
+    MustBoxFuture::new(futures::future::join(a, b))
+}
+
+/// The output of [must_try_join].
+pub type MustTryJoinOutput<'lt, A, B> = MustBoxFuture<
+    'lt,
+    Result<
+        (
+            <A as futures::future::TryFuture>::Ok,
+            <B as futures::future::TryFuture>::Ok,
+        ),
+        <A as futures::future::TryFuture>::Error,
+    >,
+>;
+
+/// Join two `TryFuture`s, returning a [MustBoxFuture] that resolves to
+/// `Ok` of a tuple of both outputs if both futures succeed, or the first
+/// `Err` encountered. Mirrors `futures::future::try_join`.
+pub fn must_try_join<'lt, A, B>(a: A, b: B) -> MustTryJoinOutput<'lt, A, B>
+where
+    A: 'lt + futures::future::TryFuture + Send,
+    B: 'lt + futures::future::TryFuture<Error = A::Error> + Send,
+    A::Ok: Send,
+    B::Ok: Send,
+{
+    MustBoxFuture::new(futures::future::try_join(a, b))
+}
+
+/// Join an iterator of futures of the same type, returning a
+/// [MustBoxFuture] that resolves to a `Vec` of their outputs once all of
+/// them have completed. Mirrors `futures::future::join_all`.
+pub fn must_join_all<'lt, I>(
+    iter: I,
+) -> MustBoxFuture<'lt, Vec<<I::Item as std::future::Future>::Output>>
+where
+    I: IntoIterator,
+    I::Item: 'lt + std::future::Future + Send,
+    <I::Item as std::future::Future>::Output: Send,
+{
+    MustBoxFuture::new(futures::future::join_all(iter))
+}
+
+/// LocalBoxFutures cannot be marked must_use because they are just type
+/// definitions. This newtype struct wraps a LocalBoxFuture with something
+/// that can be marked must_use. Unlike [MustBoxFuture], this does not
+/// require the wrapped future to be `Send`, making it usable with `!Send`
+/// futures such as those capturing `Rc` or other thread-local state.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MustLocalBoxFuture<'lt, T> {
+    sub_fut: futures::future::LocalBoxFuture<'lt, T>,
+}
+
+impl<'lt, T> MustLocalBoxFuture<'lt, T> {
+    /// Construct a new MustLocalBoxFuture from a a raw unboxed future.
+    /// Would be nice to `impl From<F: Future> for MustLocalBoxFuture`,
+    /// but blanket impls in rust core prevent this.
+    pub fn new<F: 'lt + std::future::Future<Output = T>>(f: F) -> Self {
+        Self {
+            sub_fut: futures::future::FutureExt::boxed_local(f),
+        }
+    }
+}
+
+impl<T: ?Sized> IntoMustLocalBoxFuture for T where T: std::future::Future {}
+
+/// Helper trait for converting raw unboxed futures into MustLocalBoxFutures.
+/// Would be nice to `impl<F: Future> Into<MustLocalBoxFuture> for F`,
+/// but blanket impls in rust core prevent this.
+pub trait IntoMustLocalBoxFuture: std::future::Future {
+    /// Convert this raw future into a MustLocalBoxFuture
+    fn must_box_local<'a>(self) -> MustLocalBoxFuture<'a, Self::Output>
+    where
+        Self: 'a + Sized,
+    {
+        MustLocalBoxFuture::new(self)
+    }
+}
+
+impl<'lt, T> From<futures::future::LocalBoxFuture<'lt, T>> for MustLocalBoxFuture<'lt, T> {
+    fn from(f: futures::future::LocalBoxFuture<'lt, T>) -> Self {
+        Self { sub_fut: f }
+    }
+}
+
+impl<'lt, T> std::future::Future for MustLocalBoxFuture<'lt, T> {
+    type Output = T;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<Self::Output> {
+        std::future::Future::poll(self.sub_fut.as_mut(), cx)
+    }
+}
+
+impl<'lt, T> std::fmt::Debug for MustLocalBoxFuture<'lt, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MustLocalBoxFuture").finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +340,117 @@ mod tests {
         }
         assert_eq!("test2", get_future().await);
     }
+
+    #[tokio::test]
+    pub async fn must_local_box_future_is_debug() {
+        fn get_future() -> MustLocalBoxFuture<'static, &'static str> {
+            async { "test3" }.boxed_local().into()
+        }
+        assert_eq!("MustLocalBoxFuture", &format!("{:?}", get_future()));
+    }
+
+    #[tokio::test]
+    pub async fn must_local_box_future_can_still_process() {
+        fn get_future() -> MustLocalBoxFuture<'static, &'static str> {
+            async { "test3" }.boxed_local().into()
+        }
+        assert_eq!("test3", get_future().await);
+    }
+
+    #[tokio::test]
+    pub async fn must_local_box_future_with_new() {
+        fn get_future() -> MustLocalBoxFuture<'static, &'static str> {
+            MustLocalBoxFuture::new(async { "test3" })
+        }
+        assert_eq!("test3", get_future().await);
+    }
+
+    #[tokio::test]
+    pub async fn must_local_box_future_with_must_box_local() {
+        fn get_future() -> MustLocalBoxFuture<'static, &'static str> {
+            async { "test3" }.must_box_local()
+        }
+        assert_eq!("test3", get_future().await);
+    }
+
+    struct TestBuilder;
+
+    impl std::future::IntoFuture for TestBuilder {
+        type Output = &'static str;
+        type IntoFuture = BoxFuture<'static, &'static str>;
+
+        fn into_future(self) -> Self::IntoFuture {
+            async { "test4" }.boxed()
+        }
+    }
+
+    #[tokio::test]
+    pub async fn must_box_future_with_from_into() {
+        fn get_future() -> MustBoxFuture<'static, &'static str> {
+            MustBoxFuture::from_into(TestBuilder)
+        }
+        assert_eq!("test4", get_future().await);
+    }
+
+    #[tokio::test]
+    pub async fn must_box_future_with_must_box_into() {
+        fn get_future() -> MustBoxFuture<'static, &'static str> {
+            TestBuilder.must_box_into()
+        }
+        assert_eq!("test4", get_future().await);
+    }
+
+    #[tokio::test]
+    pub async fn must_box_future_now_or_never_ready() {
+        let fut: MustBoxFuture<'static, &'static str> = async { "test5" }.boxed().into();
+        assert_eq!(Some("test5"), fut.now_or_never());
+    }
+
+    #[tokio::test]
+    pub async fn must_box_future_now_or_never_pending() {
+        let fut: MustBoxFuture<'static, ()> = futures::future::pending().boxed().into();
+        assert_eq!(None, fut.now_or_never());
+    }
+
+    #[tokio::test]
+    pub async fn must_box_future_poll_immediate_ready() {
+        let waker = futures::task::noop_waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+        let mut fut: MustBoxFuture<'static, &'static str> = async { "test6" }.boxed().into();
+        assert_eq!(std::task::Poll::Ready("test6"), fut.poll_immediate(&mut cx));
+    }
+
+    #[tokio::test]
+    pub async fn must_box_future_poll_immediate_pending() {
+        let waker = futures::task::noop_waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+        let mut fut: MustBoxFuture<'static, ()> = futures::future::pending().boxed().into();
+        assert_eq!(std::task::Poll::<()>::Pending, fut.poll_immediate(&mut cx));
+    }
+
+    #[tokio::test]
+    pub async fn must_join_combines_both_outputs() {
+        let fut = must_join(async { "a" }, async { "b" });
+        assert_eq!(("a", "b"), fut.await);
+    }
+
+    #[tokio::test]
+    pub async fn must_try_join_combines_ok_outputs() {
+        let fut: MustBoxFuture<'static, Result<(&str, &str), &str>> =
+            must_try_join(async { Ok("a") }, async { Ok("b") });
+        assert_eq!(Ok(("a", "b")), fut.await);
+    }
+
+    #[tokio::test]
+    pub async fn must_try_join_short_circuits_on_err() {
+        let fut: MustBoxFuture<'static, Result<(&str, &str), &str>> =
+            must_try_join(async { Err("oops") }, async { Ok("b") });
+        assert_eq!(Err("oops"), fut.await);
+    }
+
+    #[tokio::test]
+    pub async fn must_join_all_combines_all_outputs() {
+        let fut = must_join_all((1..=3).map(|n| async move { n }));
+        assert_eq!(vec![1, 2, 3], fut.await);
+    }
 }
@@ -0,0 +1,160 @@
+//! Streams have exactly the same forgetfulness hazard as futures: a
+//! constructed-but-never-polled stream silently does nothing. This module
+//! mirrors the top-level [MustFuture](crate::MustFuture) /
+//! [MustBoxFuture](crate::MustBoxFuture) newtypes for
+//! [`futures::Stream`](futures::Stream).
+
+use futures::stream::BoxStream;
+use futures::stream::Stream;
+
+/// Wrap a stream that may or may not be marked must_use with a newtype
+/// that is marked must_use.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct MustStream<S: Stream> {
+    sub_stream: S,
+}
+
+impl<S: Stream> MustStream<S> {
+    pin_utils::unsafe_pinned!(sub_stream: S);
+}
+
+impl<S: Stream> From<S> for MustStream<S> {
+    fn from(s: S) -> Self {
+        Self { sub_stream: s }
+    }
+}
+
+impl<S: Stream> Stream for MustStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let p: std::pin::Pin<&mut S> = self.sub_stream();
+        Stream::poll_next(p, cx)
+    }
+}
+
+impl<S: Stream + std::marker::Unpin> std::marker::Unpin for MustStream<S> {}
+
+impl<S: Stream> std::fmt::Debug for MustStream<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MustStream").finish()
+    }
+}
+
+/// BoxStreams cannot be marked must_use because they are just type
+/// definitions. This newtype struct wraps a BoxStream with something that
+/// can be marked must_use.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct MustBoxStream<'lt, T> {
+    sub_stream: BoxStream<'lt, T>,
+}
+
+impl<'lt, T> MustBoxStream<'lt, T> {
+    /// Construct a new MustBoxStream from a a raw unboxed stream.
+    /// Would be nice to `impl From<S: Stream> for MustBoxStream`,
+    /// but blanket impls in rust core prevent this.
+    pub fn new<S: 'lt + Stream<Item = T> + Send>(s: S) -> Self {
+        Self {
+            sub_stream: futures::stream::StreamExt::boxed(s),
+        }
+    }
+}
+
+impl<T: ?Sized> IntoMustBoxStream for T where T: Stream {}
+
+/// Helper trait for converting raw unboxed streams into MustBoxStreams.
+/// Would be nice to `impl<S: Stream> Into<MustBoxStream> for S`,
+/// but blanket impls in rust core prevent this.
+pub trait IntoMustBoxStream: Stream {
+    /// Convert this raw stream into a MustBoxStream
+    fn must_box_stream<'a>(self) -> MustBoxStream<'a, Self::Item>
+    where
+        Self: 'a + Sized + Send,
+    {
+        MustBoxStream::new(self)
+    }
+}
+
+impl<'lt, T> From<BoxStream<'lt, T>> for MustBoxStream<'lt, T> {
+    fn from(s: BoxStream<'lt, T>) -> Self {
+        Self { sub_stream: s }
+    }
+}
+
+impl<'lt, T> Stream for MustBoxStream<'lt, T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        Stream::poll_next(self.sub_stream.as_mut(), cx)
+    }
+}
+
+impl<'lt, T> std::fmt::Debug for MustBoxStream<'lt, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MustBoxStream").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::StreamExt;
+
+    #[tokio::test]
+    pub async fn must_box_stream_is_debug() {
+        fn get_stream() -> MustBoxStream<'static, &'static str> {
+            futures::stream::iter(vec!["test1"]).boxed().into()
+        }
+        assert_eq!("MustBoxStream", &format!("{:?}", get_stream()));
+    }
+
+    #[tokio::test]
+    pub async fn must_box_stream_can_still_process() {
+        fn get_stream() -> MustBoxStream<'static, &'static str> {
+            futures::stream::iter(vec!["test1"]).boxed().into()
+        }
+        let out: Vec<_> = get_stream().collect().await;
+        assert_eq!(vec!["test1"], out);
+    }
+
+    #[tokio::test]
+    pub async fn must_box_stream_with_new() {
+        fn get_stream() -> MustBoxStream<'static, &'static str> {
+            MustBoxStream::new(futures::stream::iter(vec!["test1"]))
+        }
+        let out: Vec<_> = get_stream().collect().await;
+        assert_eq!(vec!["test1"], out);
+    }
+
+    #[tokio::test]
+    pub async fn must_box_stream_with_must_box_stream() {
+        fn get_stream() -> MustBoxStream<'static, &'static str> {
+            futures::stream::iter(vec!["test1"]).must_box_stream()
+        }
+        let out: Vec<_> = get_stream().collect().await;
+        assert_eq!(vec!["test1"], out);
+    }
+
+    #[tokio::test]
+    pub async fn must_stream_is_debug() {
+        fn get_stream() -> MustStream<BoxStream<'static, &'static str>> {
+            futures::stream::iter(vec!["test2"]).boxed().into()
+        }
+        assert_eq!("MustStream", &format!("{:?}", get_stream()));
+    }
+
+    #[tokio::test]
+    pub async fn must_stream_can_still_process() {
+        fn get_stream() -> MustStream<BoxStream<'static, &'static str>> {
+            futures::stream::iter(vec!["test2"]).boxed().into()
+        }
+        let out: Vec<_> = get_stream().collect().await;
+        assert_eq!(vec!["test2"], out);
+    }
+}